@@ -18,30 +18,117 @@ use std::iter::FromIterator;
 /// or the [freestanding gatherr function](fn.gatherr.html) instead
 pub struct Gatherr<T, E>(pub Result<T, E>);
 
+/// Builds `T` from every `Ok` item up to (not including) the first `Err` via `build_ok`,
+/// then, only if an `Err` was found, builds `E` from that error followed by every later
+/// one via `build_err`. This is the shared "collect the Ok prefix, then drain the
+/// remaining Errs" dance behind [`Gatherr`], [`gatherr_in`] and [`gatherr_flatten`]
+fn gather<A, B, T, E>(
+    mut iter: impl Iterator<Item = Result<A, B>>,
+    build_ok: impl FnOnce(&mut dyn Iterator<Item = A>) -> T,
+    build_err: impl FnOnce(&mut dyn Iterator<Item = B>) -> E,
+) -> Result<T, E> {
+    let mut first_err = None;
+    let ok = build_ok(&mut (&mut iter).scan((), |_, i| match i {
+        Ok(v) => Some(v),
+        Err(e) => {
+            first_err = Some(e);
+            None
+        }
+    }));
+    if let Some(first_err) = first_err {
+        drop(ok);
+        Err(build_err(
+            &mut std::iter::once(first_err).chain(iter.filter_map(Result::err)),
+        ))
+    } else {
+        Ok(ok)
+    }
+}
+
 impl<A, B, T: FromIterator<A>, E: FromIterator<B>> FromIterator<Result<A, B>> for Gatherr<T, E> {
     fn from_iter<I: IntoIterator<Item = Result<A, B>>>(iter: I) -> Self {
-        let mut iter = iter.into_iter();
-        let mut first_err = None;
-        let ok = (&mut iter)
-            .scan((), |_, i| match i {
-                Ok(v) => Some(v),
-                Err(e) => {
-                    first_err = Some(e);
-                    None
-                }
-            })
-            .collect();
-        Gatherr(if let Some(first_err) = first_err {
-            drop(ok);
-            Err(std::iter::once(first_err)
-                .chain(iter.filter_map(|r| r.err()))
-                .collect())
-        } else {
-            Ok(ok)
-        })
+        Gatherr(gather(
+            iter.into_iter(),
+            |ok| ok.collect(),
+            |err| err.collect(),
+        ))
+    }
+}
+
+/// A newtype implementing FromIterator to collect into a pair of collections,
+/// one holding every `Ok` value and the other every `Err` value, regardless of
+/// whether any errors occurred
+///
+/// Unlike [`Gatherr`], which discards the `Ok` values once an `Err` is seen,
+/// this keeps both sides in a single pass - useful for validation passes that
+/// want to report every valid record alongside every diagnostic
+///
+/// ```
+/// # use gatherr::GatherrPartition;
+/// let v = vec![Ok("a"), Err(1), Ok("b"), Err(2)];
+///
+/// let GatherrPartition((oks, errs)): GatherrPartition<Vec<&str>, Vec<u32>>
+///     = v.into_iter().collect();
+///
+/// assert_eq!(oks, vec!["a", "b"]);
+/// assert_eq!(errs, vec![1, 2]);
+/// ```
+///
+/// Using this directly can be awkward due to the necessary additional type
+/// annotation. Consider using [the extension trait method](trait.IterExt.html#method.gatherr_partition)
+/// or the [freestanding gatherr_partition function](fn.gatherr_partition.html) instead
+pub struct GatherrPartition<T, E>(pub (T, E));
+
+impl<A, B, T: Default + Extend<A>, E: Default + Extend<B>> FromIterator<Result<A, B>>
+    for GatherrPartition<T, E>
+{
+    fn from_iter<I: IntoIterator<Item = Result<A, B>>>(iter: I) -> Self {
+        let mut oks = T::default();
+        let mut errs = E::default();
+        for item in iter {
+            match item {
+                Ok(v) => oks.extend(Some(v)),
+                Err(e) => errs.extend(Some(e)),
+            }
+        }
+        GatherrPartition((oks, errs))
     }
 }
 
+/// A lazy, resumable circuit breaker over an iterator of `Result`s
+///
+/// Yields the `Ok` payload of each item in turn, and returns `None` the
+/// instant an `Err` is reached. The offending error is stashed in [`caught`](TripIter::caught)
+/// rather than returned, so it can be inspected once the borrow on the
+/// underlying iterator ends. Crucially, the underlying iterator is left
+/// positioned immediately after the offending item, so the caller can resume
+/// consuming it afterwards
+///
+/// Construct one with [`IterExt::trip`], or use [`IterExt::with_trip`] to
+/// keep the borrow scoped to a closure
+pub struct TripIter<'a, A, B, I: Iterator<Item = Result<A, B>>> {
+    iter: &'a mut I,
+    /// The error that tripped the iterator, if any
+    pub caught: Option<B>,
+}
+
+impl<'a, A, B, I: Iterator<Item = Result<A, B>>> Iterator for TripIter<'a, A, B, I> {
+    type Item = A;
+
+    fn next(&mut self) -> Option<A> {
+        if self.caught.is_some() {
+            return None;
+        }
+        match self.iter.next() {
+            Some(Ok(v)) => Some(v),
+            Some(Err(e)) => {
+                self.caught = Some(e);
+                None
+            }
+            None => None,
+        }
+    }
+}
 
 /// An extension trait for iterators of `Result`s to easily collect without the
 /// extra newtype
@@ -61,10 +148,179 @@ pub trait IterExt<A, B>: Iterator<Item = Result<A, B>> + Sized {
         let Gatherr(result) = self.collect();
         result
     }
+
+    /// Collect every Ok and every Err value from this iterator into a pair
+    /// of collections, regardless of whether any errors occurred
+    ///
+    /// ```
+    /// use gatherr::IterExt;
+    /// let v = vec![Ok("a"), Err(1), Ok("b"), Err(2)];
+    ///
+    /// let (oks, errs): (Vec<&str>, Vec<u32>) = v.into_iter().gatherr_partition();
+    ///
+    /// assert_eq!(oks, vec!["a", "b"]);
+    /// assert_eq!(errs, vec![1, 2]);
+    /// ```
+    fn gatherr_partition<T: Default + Extend<A>, E: Default + Extend<B>>(self) -> (T, E) {
+        let GatherrPartition(result) = self.collect();
+        result
+    }
+
+    /// Wrap this iterator in a [`TripIter`] that yields the `Ok` payloads and
+    /// stops at the first `Err`, without consuming the tail
+    ///
+    /// ```
+    /// use gatherr::IterExt;
+    /// let mut v = vec![Ok(1), Ok(2), Err("bang"), Ok(3)].into_iter();
+    ///
+    /// let collected: Vec<_> = v.trip().collect();
+    /// assert_eq!(collected, vec![1, 2]);
+    ///
+    /// // the iterator is left positioned right after the error, so it can
+    /// // still be resumed
+    /// assert_eq!(v.next(), Some(Ok(3)));
+    /// ```
+    fn trip(&mut self) -> TripIter<'_, A, B, Self> {
+        TripIter {
+            iter: self,
+            caught: None,
+        }
+    }
+
+    /// Run `f` over a [`TripIter`] wrapping this iterator, returning its
+    /// result alongside whatever error tripped the iterator, if any
+    ///
+    /// This keeps the borrow on the underlying iterator scoped to the
+    /// closure, rather than living as long as the returned `TripIter` would
+    ///
+    /// ```
+    /// use gatherr::IterExt;
+    /// let v = vec![Ok(1), Ok(2), Err("bang"), Ok(3)];
+    ///
+    /// let (sum, caught) = v.into_iter().with_trip(|trip| trip.sum::<i32>());
+    ///
+    /// assert_eq!(sum, 3);
+    /// assert_eq!(caught, Some("bang"));
+    /// ```
+    fn with_trip<R>(
+        &mut self,
+        f: impl FnOnce(&mut TripIter<'_, A, B, Self>) -> R,
+    ) -> (R, Option<B>) {
+        let mut trip = self.trip();
+        let result = f(&mut trip);
+        (result, trip.caught)
+    }
+
+    /// Collect all Ok or Err values from this iterator into a single
+    /// `Result` of collections, built in a caller-supplied allocator
+    /// instead of the global heap
+    ///
+    /// See [`gatherr_in`] for a full example
+    fn gatherr_in<
+        T: FromIteratorIn<A, Alloc = Alloc>,
+        E: FromIteratorIn<B, Alloc = Alloc>,
+        Alloc: Clone,
+    >(
+        self,
+        alloc: Alloc,
+    ) -> Result<T, E> {
+        gatherr_in(self, alloc)
+    }
+
+    /// Collect all Ok or Err values from this iterator into a single
+    /// `Result` of collections, flattening each `Ok`'s inner collection
+    /// into the single success collection
+    ///
+    /// Saves the two-step `collect::<Result<Vec<Vec<_>>, _>>()?.into_iter().flatten().collect()`
+    /// dance while keeping this crate's all-errors semantics
+    ///
+    /// ```
+    /// use gatherr::IterExt;
+    /// let v = vec![Ok(vec!["a", "b"]), Err(1), Ok(vec!["c"]), Err(2)];
+    ///
+    /// let result: Result<Vec<&str>, Vec<u32>> = v.into_iter().gatherr_flatten();
+    ///
+    /// assert_eq!(result, Err(vec![1, 2]));
+    /// ```
+    fn gatherr_flatten<Item, T: FromIterator<Item>, E: FromIterator<B>>(self) -> Result<T, E>
+    where
+        A: IntoIterator<Item = Item>,
+    {
+        gatherr_flatten(self)
+    }
 }
 
 impl<A, B, I: Iterator<Item = Result<A, B>> + Sized> IterExt<A, B> for I {}
 
+/// A counterpart to [`std::iter::FromIterator`] that builds a collection
+/// through a caller-supplied allocator handle, following bumpalo's
+/// `FromIteratorIn` design
+///
+/// Implement this for collection types that can be built in an arena or
+/// other custom allocator, so that [`gatherr_in`] can gather both the
+/// success and error collections into the same allocator rather than only
+/// through `std`'s global-heap-backed `FromIterator`
+///
+/// Note that [`gatherr_in`] still has to build the `Ok` run that precedes
+/// the first `Err` before it knows whether one exists, exactly like
+/// [`Gatherr`] does for the global heap. For a `Vec`-backed collection that
+/// wasted allocation is reclaimed on `drop`; most arena/bump allocators
+/// can't reclaim an individual allocation that way, only by resetting the
+/// whole arena, so a long `Ok` run ending in an `Err` permanently consumes
+/// that much arena space
+pub trait FromIteratorIn<A> {
+    /// The allocator handle this collection is built in
+    type Alloc;
+
+    /// Build `Self` from `iter`, allocating its storage via `alloc`
+    fn from_iter_in<I: IntoIterator<Item = A>>(iter: I, alloc: Self::Alloc) -> Self;
+}
+
+/// Collect all Ok or Err values from an iterator into a single `Result` of
+/// collections, built in a caller-supplied allocator instead of the global
+/// heap
+///
+/// See the caveat on [`FromIteratorIn`] about arena allocators and a
+/// discarded `Ok` run
+///
+/// ```
+/// use gatherr::{gatherr_in, FromIteratorIn};
+///
+/// #[derive(Clone)]
+/// struct ArenaHandle;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct ArenaVec<A>(Vec<A>);
+///
+/// impl<A> FromIteratorIn<A> for ArenaVec<A> {
+///     type Alloc = ArenaHandle;
+///
+///     fn from_iter_in<I: IntoIterator<Item = A>>(iter: I, _alloc: ArenaHandle) -> Self {
+///         ArenaVec(iter.into_iter().collect())
+///     }
+/// }
+///
+/// let v = vec![Ok("a"), Err(1), Ok("b"), Err(2)];
+///
+/// let result: Result<ArenaVec<&str>, ArenaVec<u32>> = gatherr_in(v, ArenaHandle);
+///
+/// assert_eq!(result, Err(ArenaVec(vec![1, 2])));
+/// ```
+pub fn gatherr_in<A, B, T, E, Alloc, I>(iter: I, alloc: Alloc) -> Result<T, E>
+where
+    T: FromIteratorIn<A, Alloc = Alloc>,
+    E: FromIteratorIn<B, Alloc = Alloc>,
+    Alloc: Clone,
+    I: IntoIterator<Item = Result<A, B>>,
+{
+    let ok_alloc = alloc.clone();
+    gather(
+        iter.into_iter(),
+        move |ok| T::from_iter_in(ok, ok_alloc),
+        move |err| E::from_iter_in(err, alloc),
+    )
+}
+
 /// Collect all Ok or Err values from an iterator into a single `Result` of
 /// collections
 ///
@@ -89,6 +345,230 @@ pub fn gatherr<
     result
 }
 
+/// Collect every Ok and every Err value from an iterator into a pair of
+/// collections, regardless of whether any errors occurred
+///
+/// ```
+/// # use gatherr::gatherr_partition;
+/// let v = vec![Ok("a"), Err(1), Ok("b"), Err(2)];
+///
+/// let (oks, errs): (Vec<&str>, Vec<u32>) = gatherr_partition(v);
+///
+/// assert_eq!(oks, vec!["a", "b"]);
+/// assert_eq!(errs, vec![1, 2]);
+/// ```
+pub fn gatherr_partition<
+    A,
+    B,
+    T: Default + Extend<A>,
+    E: Default + Extend<B>,
+    I: IntoIterator<Item = Result<A, B>>,
+>(
+    iter: I,
+) -> (T, E) {
+    let GatherrPartition(result) = iter.into_iter().collect();
+    result
+}
+
+/// Collect all Ok or Err values from an iterator into a single `Result` of
+/// collections, flattening each `Ok`'s inner collection into the single
+/// success collection
+///
+/// ```
+/// # use gatherr::gatherr_flatten;
+/// let v = vec![Ok(vec!["a", "b"]), Err(1), Ok(vec!["c"]), Err(2)];
+///
+/// let result: Result<Vec<&str>, Vec<u32>> = gatherr_flatten(v);
+///
+/// assert_eq!(result, Err(vec![1, 2]));
+/// ```
+pub fn gatherr_flatten<A, B, C, T, E, I>(iter: I) -> Result<T, E>
+where
+    C: IntoIterator<Item = A>,
+    T: FromIterator<A>,
+    E: FromIterator<B>,
+    I: IntoIterator<Item = Result<C, B>>,
+{
+    gather(
+        iter.into_iter(),
+        |ok| ok.flat_map(IntoIterator::into_iter).collect(),
+        |err| err.collect(),
+    )
+}
+
+mod sealed {
+    pub trait Sealed {}
+    impl<A, B> Sealed for Result<A, B> {}
+    impl<A> Sealed for Option<A> {}
+}
+
+/// Describes a short-circuiting item type with a success payload and a
+/// failure residual, generalising `gatherr` over `Result` and `Option`
+/// (and any future such type, were one added to this crate) rather than
+/// over `Result` alone
+///
+/// This is sealed: it's only implemented for `Result<A, B>` and `Option<A>`
+pub trait Gatherable: sealed::Sealed {
+    /// The payload carried on success
+    type Success;
+    /// The payload carried on failure/absence
+    type Residual;
+    /// The type this gathers into, parameterised by the success and
+    /// residual collections - `Result<T, E>` for `Result`, `Option<T>` for
+    /// `Option`
+    type Gathered<T, E>;
+
+    /// Convert into a plain `Result`, so the existing [`Gatherr`] machinery
+    /// can do the actual gathering
+    fn into_result(self) -> Result<Self::Success, Self::Residual>;
+
+    /// Wrap a successfully gathered collection back into [`Gathered`](Gatherable::Gathered)
+    fn gathered_ok<T, E>(ok: T) -> Self::Gathered<T, E>;
+
+    /// Wrap a gathered residual collection back into [`Gathered`](Gatherable::Gathered)
+    fn gathered_err<T, E>(err: E) -> Self::Gathered<T, E>;
+}
+
+impl<A, B> Gatherable for Result<A, B> {
+    type Success = A;
+    type Residual = B;
+    type Gathered<T, E> = Result<T, E>;
+
+    fn into_result(self) -> Result<A, B> {
+        self
+    }
+
+    fn gathered_ok<T, E>(ok: T) -> Result<T, E> {
+        Ok(ok)
+    }
+
+    fn gathered_err<T, E>(err: E) -> Result<T, E> {
+        Err(err)
+    }
+}
+
+impl<A> Gatherable for Option<A> {
+    type Success = A;
+    type Residual = ();
+    type Gathered<T, E> = Option<T>;
+
+    fn into_result(self) -> Result<A, ()> {
+        self.ok_or(())
+    }
+
+    fn gathered_ok<T, E>(ok: T) -> Option<T> {
+        Some(ok)
+    }
+
+    fn gathered_err<T, E>(_err: E) -> Option<T> {
+        None
+    }
+}
+
+/// An extension trait for iterators of any [`Gatherable`] item (`Result` or
+/// `Option`) to gather them without the extra newtype
+pub trait GatherrAnyExt<G: Gatherable>: Iterator<Item = G> + Sized {
+    /// Collect all success or all residual values from this iterator into
+    /// `G::Gathered<T, E>` - a plain `Result<T, E>` for an iterator of
+    /// `Result`, exactly like [`IterExt::gatherr`], or an `Option<T>` for an
+    /// iterator of `Option`, holding `Some(T)` only if nothing was missing
+    ///
+    /// For an iterator of `Option` specifically, [`OptionIterExt::gatherr_opt`]
+    /// does the same thing without needing a throwaway residual collection
+    /// type to satisfy `E`
+    ///
+    /// ```
+    /// use gatherr::GatherrAnyExt;
+    /// let v = vec![Some(1), Some(2), None, Some(3)];
+    ///
+    /// let result: Option<Vec<i32>> = v.into_iter().gatherr_any::<Vec<i32>, Vec<()>>();
+    ///
+    /// assert_eq!(result, None);
+    ///
+    /// let v = vec![Some(1), Some(2), Some(3)];
+    /// let result: Option<Vec<i32>> = v.into_iter().gatherr_any::<Vec<i32>, Vec<()>>();
+    ///
+    /// assert_eq!(result, Some(vec![1, 2, 3]));
+    /// ```
+    fn gatherr_any<T: FromIterator<G::Success>, E: FromIterator<G::Residual>>(
+        self,
+    ) -> G::Gathered<T, E> {
+        gatherr_any(self)
+    }
+}
+
+impl<G: Gatherable, I: Iterator<Item = G> + Sized> GatherrAnyExt<G> for I {}
+
+/// Collect all success or all residual values from an iterator of any
+/// [`Gatherable`] item (`Result` or `Option`)
+///
+/// ```
+/// # use gatherr::gatherr_any;
+/// let v = vec![Ok("a"), Err(1), Ok("b"), Err(2)];
+///
+/// let result: Result<Vec<&str>, Vec<u32>> = gatherr_any(v);
+///
+/// assert_eq!(result, Err(vec![1, 2]));
+/// ```
+pub fn gatherr_any<G, T, E, I>(iter: I) -> G::Gathered<T, E>
+where
+    G: Gatherable,
+    T: FromIterator<G::Success>,
+    E: FromIterator<G::Residual>,
+    I: IntoIterator<Item = G>,
+{
+    let Gatherr(result) = iter.into_iter().map(Gatherable::into_result).collect();
+    match result {
+        Ok(t) => G::gathered_ok(t),
+        Err(e) => G::gathered_err(e),
+    }
+}
+
+/// An extension trait for iterators of `Option`s to collect them without
+/// the throwaway residual collection type [`GatherrAnyExt::gatherr_any`]
+/// needs to satisfy its generic `E`
+pub trait OptionIterExt<A>: Iterator<Item = Option<A>> + Sized {
+    /// Collect all payloads from this iterator into `Some(T)`, or `None` if
+    /// any item was missing
+    ///
+    /// ```
+    /// use gatherr::OptionIterExt;
+    /// let v = vec![Some(1), Some(2), None, Some(3)];
+    ///
+    /// let result: Option<Vec<i32>> = v.into_iter().gatherr_opt();
+    ///
+    /// assert_eq!(result, None);
+    ///
+    /// let v = vec![Some(1), Some(2), Some(3)];
+    /// let result: Option<Vec<i32>> = v.into_iter().gatherr_opt();
+    ///
+    /// assert_eq!(result, Some(vec![1, 2, 3]));
+    /// ```
+    fn gatherr_opt<T: FromIterator<A>>(self) -> Option<T> {
+        gatherr_opt(self)
+    }
+}
+
+impl<A, I: Iterator<Item = Option<A>> + Sized> OptionIterExt<A> for I {}
+
+/// Collect all payloads from an iterator of `Option`s into `Some(T)`, or
+/// `None` if any item was missing
+///
+/// This is a dedicated convenience for `Option`; to share gathering code
+/// that's generic over `Result` and `Option` alike, see [`gatherr_any`]
+///
+/// ```
+/// # use gatherr::gatherr_opt;
+/// let v = vec![Some(1), Some(2), None, Some(3)];
+///
+/// let result: Option<Vec<i32>> = gatherr_opt(v);
+///
+/// assert_eq!(result, None);
+/// ```
+pub fn gatherr_opt<A, T: FromIterator<A>, I: IntoIterator<Item = Option<A>>>(iter: I) -> Option<T> {
+    iter.into_iter().collect()
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -148,4 +628,161 @@ mod test {
         let result: Result<Vec<String>, Vec<String>> = std::iter::empty().gatherr();
         assert_eq!(result, Ok(Vec::new()));
     }
+
+    #[test]
+    fn partition_keeps_both_sides() {
+        let v: Vec<Result<String, String>> = vec![
+            Ok("Hello".to_owned()),
+            Err("Goodbye".to_owned()),
+            Ok("World".to_owned()),
+            Err("cruel".to_owned()),
+            Err("world".to_owned()),
+        ];
+        let (oks, errs): (Vec<_>, Vec<_>) = v.into_iter().gatherr_partition();
+
+        assert_eq!(&oks, &["Hello", "World"]);
+        assert_eq!(&errs, &["Goodbye", "cruel", "world"]);
+    }
+
+    #[test]
+    fn partition_all_ok() {
+        let v: Vec<Result<_, String>> = vec![Ok("a"), Ok("b")];
+        let (oks, errs): (Vec<_>, Vec<_>) = v.into_iter().gatherr_partition();
+
+        assert_eq!(&oks, &["a", "b"]);
+        assert!(errs.is_empty());
+    }
+
+    #[test]
+    fn trip_stops_at_first_err_and_is_resumable() {
+        let mut v = vec![Ok(1), Ok(2), Err("bang"), Ok(3), Ok(4)].into_iter();
+
+        let collected: Vec<_> = v.trip().collect();
+        assert_eq!(collected, vec![1, 2]);
+
+        let rest: Vec<_> = v.collect();
+        assert_eq!(rest, vec![Ok(3), Ok(4)]);
+    }
+
+    #[test]
+    fn trip_reports_caught_error_after_borrow_ends() {
+        let mut v = vec![Ok(1), Err("bang"), Ok(2)].into_iter();
+        let caught = {
+            let mut trip = v.trip();
+            (&mut trip).for_each(drop);
+            trip.caught
+        };
+
+        assert_eq!(caught, Some("bang"));
+    }
+
+    #[test]
+    fn trip_no_err_leaves_caught_empty() {
+        let mut v = vec![Ok::<_, &str>(1), Ok(2)].into_iter();
+        let mut trip = v.trip();
+        let collected: Vec<_> = (&mut trip).collect();
+
+        assert_eq!(collected, vec![1, 2]);
+        assert_eq!(trip.caught, None);
+    }
+
+    #[test]
+    fn with_trip_scopes_the_borrow() {
+        let v = vec![Ok(1), Ok(2), Err("bang"), Ok(3)];
+        let (sum, caught) = v.into_iter().with_trip(|trip| trip.sum::<i32>());
+
+        assert_eq!(sum, 3);
+        assert_eq!(caught, Some("bang"));
+    }
+
+    #[derive(Clone)]
+    struct StubAlloc;
+
+    #[derive(Debug)]
+    struct StubVec<A>(Vec<A>);
+
+    impl<A> FromIteratorIn<A> for StubVec<A> {
+        type Alloc = StubAlloc;
+
+        fn from_iter_in<I: IntoIterator<Item = A>>(iter: I, _alloc: StubAlloc) -> Self {
+            StubVec(iter.into_iter().collect())
+        }
+    }
+
+    #[test]
+    fn gatherr_in_builds_via_the_allocator() {
+        let v = vec![Ok("a"), Err(1), Ok("b"), Err(2)];
+        let result: Result<StubVec<&str>, StubVec<u32>> = v.into_iter().gatherr_in(StubAlloc);
+
+        assert_eq!(result.unwrap_err().0, vec![1, 2]);
+    }
+
+    #[test]
+    fn gatherr_in_all_ok() {
+        let v: Vec<Result<_, i32>> = vec![Ok("a"), Ok("b")];
+        let result: Result<StubVec<&str>, StubVec<i32>> = v.into_iter().gatherr_in(StubAlloc);
+
+        assert_eq!(result.unwrap().0, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn gatherr_any_on_result_matches_gatherr() {
+        let v: Vec<Result<_, i32>> = vec![Ok("a"), Err(1), Ok("b"), Err(2)];
+        let result: Result<Vec<_>, Vec<_>> = v.into_iter().gatherr_any();
+
+        assert_eq!(result, Err(vec![1, 2]));
+    }
+
+    #[test]
+    fn gatherr_any_on_option_gathers_some() {
+        let v = vec![Some(1), Some(2), Some(3)];
+        let result: Option<Vec<_>> = v.into_iter().gatherr_any::<Vec<_>, Vec<()>>();
+
+        assert_eq!(result, Some(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn gatherr_any_on_option_reports_missing() {
+        let v = vec![Some(1), None, Some(3), None];
+        let result: Option<Vec<_>> = v.into_iter().gatherr_any::<Vec<_>, Vec<()>>();
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn gatherr_opt_gathers_some() {
+        let v = vec![Some(1), Some(2), Some(3)];
+        let result: Option<Vec<_>> = v.into_iter().gatherr_opt();
+
+        assert_eq!(result, Some(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn gatherr_opt_reports_missing() {
+        let v = vec![Some(1), None, Some(3), None];
+        let result: Option<Vec<_>> = v.into_iter().gatherr_opt();
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn flatten_gather_concatenates_ok_collections() {
+        let v: Vec<Result<Vec<&str>, i32>> = vec![Ok(vec!["Hello", "World"]), Ok(vec!["!"])];
+        let result: Result<Vec<_>, Vec<_>> = v.into_iter().gatherr_flatten();
+
+        assert_eq!(&result.unwrap(), &["Hello", "World", "!"]);
+    }
+
+    #[test]
+    fn flatten_gather_collects_all_errors() {
+        let v: Vec<Result<Vec<&str>, _>> = vec![
+            Ok(vec!["Hello"]),
+            Err("Goodbye".to_owned()),
+            Ok(vec!["World", "!"]),
+            Err("cruel".to_owned()),
+        ];
+        let result: Result<Vec<_>, Vec<_>> = v.into_iter().gatherr_flatten();
+
+        assert_eq!(&result.unwrap_err(), &["Goodbye", "cruel"]);
+    }
 }